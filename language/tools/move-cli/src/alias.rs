@@ -0,0 +1,149 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of command aliases (built-in short names and user-defined ones read from
+//! `Move.toml`'s `[alias]` table or `~/.move/config.toml`) and a "did you mean" suggester for
+//! mistyped subcommand names.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Built-in short aliases for the most common subcommands, mirroring cargo's alias table.
+const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("b", "build"),
+    ("c", "coverage"),
+    ("d", "disassemble"),
+    ("t", "test"),
+];
+
+/// The real subcommand names, used to suggest a `did-you-mean` correction for a typo.
+const COMMAND_NAMES: &[&str] = &[
+    "build",
+    "clean",
+    "coverage",
+    "disassemble",
+    "errmap",
+    "info",
+    "new",
+    "prove",
+    "test",
+    "sandbox",
+    "experimental",
+];
+
+/// Resolve `name` to the command line it should expand to: a built-in alias, a user-defined one
+/// from the package's `Move.toml` or `~/.move/config.toml`, or `None` if `name` isn't an alias
+/// (e.g. it's already a real subcommand, or simply unknown). Package aliases shadow global ones,
+/// which shadow the built-ins.
+pub fn resolve(name: &str, package_path: Option<&Path>) -> Option<String> {
+    if let Some(expansion) = package_path.and_then(|p| load_package_aliases(p).remove(name)) {
+        return Some(expansion);
+    }
+    if let Some(expansion) = load_global_aliases().remove(name) {
+        return Some(expansion);
+    }
+    BUILTIN_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, expansion)| expansion.to_string())
+}
+
+/// Find the known command name closest to `name` by Levenshtein distance, if one is within
+/// `threshold` edits.
+pub fn suggest(name: &str, threshold: usize) -> Option<&'static str> {
+    COMMAND_NAMES
+        .iter()
+        .map(|candidate| (*candidate, levenshtein(name, candidate)))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Read the `[alias]` table out of the package's `Move.toml`, if any.
+fn load_package_aliases(package_path: &Path) -> BTreeMap<String, String> {
+    load_aliases(&package_path.join("Move.toml"))
+}
+
+/// Read the `[alias]` table out of `~/.move/config.toml`, if any.
+fn load_global_aliases() -> BTreeMap<String, String> {
+    match dirs_next::home_dir() {
+        Some(home) => load_aliases(&home.join(".move").join("config.toml")),
+        None => BTreeMap::new(),
+    }
+}
+
+fn load_aliases(path: &Path) -> BTreeMap<String, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return BTreeMap::new(),
+    };
+    let parsed: toml::Value = match toml::from_str(&contents) {
+        Ok(parsed) => parsed,
+        Err(_) => return BTreeMap::new(),
+    };
+    parsed
+        .get("alias")
+        .and_then(|a| a.as_table())
+        .map(|table| {
+            table
+                .iter()
+                .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Classic Wagner-Fischer edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let prev_above = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = prev_above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_alias_resolves() {
+        assert_eq!(resolve("b", None), Some("build".to_string()));
+        assert_eq!(resolve("t", None), Some("test".to_string()));
+    }
+
+    #[test]
+    fn unknown_name_does_not_resolve() {
+        assert_eq!(resolve("nope", None), None);
+    }
+
+    #[test]
+    fn suggest_finds_close_typo() {
+        assert_eq!(suggest("buld", 3), Some("build"));
+        assert_eq!(suggest("tset", 3), Some("test"));
+    }
+
+    #[test]
+    fn suggest_gives_up_past_the_threshold() {
+        assert_eq!(suggest("xyzzyxyzzy", 3), None);
+    }
+
+    #[test]
+    fn levenshtein_matches_known_distances() {
+        assert_eq!(levenshtein("build", "build"), 0);
+        assert_eq!(levenshtein("buld", "build"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::output::{Event, OutputSink};
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::path::PathBuf;
+
+/// Compile the package at `path` (or the current directory, if none is given).
+#[derive(Clone, Parser)]
+pub struct Build;
+
+impl Build {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        let result = config.compile_package(&root, &mut std::io::stdout());
+        sink.event(&Event::BuildFinished {
+            success: result.is_ok(),
+        });
+        result.map(|_| ())
+    }
+}
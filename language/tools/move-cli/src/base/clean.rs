@@ -0,0 +1,139 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::DEFAULT_STORAGE_DIR;
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Remove the build output (and, optionally, the sandbox storage) for a package.
+///
+/// Cleaning a subset of a workspace's packages reuses the top-level `--package` selector
+/// (`Move::package`) rather than defining its own, so there is only one `--package` flag in the
+/// whole CLI and it doesn't collide with the pre-existing global `-p`/`--path` short flag.
+#[derive(Parser)]
+#[clap(name = "clean")]
+pub struct Clean {
+    /// Also remove the sandbox storage directory (`DEFAULT_STORAGE_DIR`): resources, events, and
+    /// published module bytecode.
+    #[clap(long)]
+    sandbox: bool,
+
+    /// Print what would be deleted without removing anything.
+    #[clap(long = "dry-run")]
+    dry_run: bool,
+}
+
+impl Clean {
+    /// `packages` comes from the shared top-level `--package` selector: empty cleans every
+    /// package under the build directory, non-empty cleans just the named ones, leaving their
+    /// dependencies' cached output in place.
+    pub fn execute(
+        self,
+        package_path: Option<PathBuf>,
+        build_config: BuildConfig,
+        packages: &[String],
+    ) -> Result<()> {
+        let root = package_path.unwrap_or_else(|| PathBuf::from("."));
+        let build_dir = build_config
+            .install_dir
+            .clone()
+            .unwrap_or_else(|| root.clone())
+            .join("build");
+
+        for target in targets_to_remove(&build_dir, packages) {
+            remove(&target, self.dry_run)?;
+        }
+
+        if self.sandbox {
+            remove(&root.join(DEFAULT_STORAGE_DIR), self.dry_run)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Given the package's `build/` directory and a (possibly empty) set of `--package` selectors,
+/// return the list of paths that should be removed: the whole `build/` directory when no
+/// selectors are given, or just the named packages' subdirectories of it otherwise.
+fn targets_to_remove(build_dir: &Path, packages: &[String]) -> Vec<PathBuf> {
+    if packages.is_empty() {
+        vec![build_dir.to_path_buf()]
+    } else {
+        packages.iter().map(|name| build_dir.join(name)).collect()
+    }
+}
+
+fn remove(path: &Path, dry_run: bool) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    if dry_run {
+        println!("would remove {}", path.display());
+        return Ok(());
+    }
+    println!("removing {}", path.display());
+    if path.is_dir() {
+        fs::remove_dir_all(path)?;
+    } else {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cleans_whole_build_dir_when_no_packages_given() {
+        let build_dir = PathBuf::from("/pkg/build");
+        let targets = targets_to_remove(&build_dir, &[]);
+        assert_eq!(targets, vec![build_dir]);
+    }
+
+    #[test]
+    fn cleans_only_named_packages() {
+        let build_dir = PathBuf::from("/pkg/build");
+        let packages = vec!["A".to_string(), "B".to_string()];
+        let targets = targets_to_remove(&build_dir, &packages);
+        assert_eq!(
+            targets,
+            vec![build_dir.join("A"), build_dir.join("B")]
+        );
+    }
+
+    #[test]
+    fn dry_run_does_not_touch_the_filesystem() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("build");
+        fs::create_dir(&target).unwrap();
+
+        remove(&target, true).unwrap();
+
+        assert!(target.exists());
+    }
+
+    #[test]
+    fn remove_deletes_the_target_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("build");
+        fs::create_dir(&target).unwrap();
+
+        remove(&target, false).unwrap();
+
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn remove_is_a_no_op_when_the_path_does_not_exist() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("does-not-exist");
+
+        remove(&target, false).unwrap();
+    }
+}
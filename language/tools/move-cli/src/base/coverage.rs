@@ -0,0 +1,34 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::output::OutputSink;
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::path::PathBuf;
+
+/// Compile the package at `path` and print a coverage summary header naming the package. No
+/// coverage data is collected or read, so no coverage figures are printed.
+#[derive(Clone, Parser)]
+pub struct Coverage {
+    /// Accepted but has no effect: no per-function coverage is printed.
+    #[clap(long = "per-function")]
+    per_function: bool,
+}
+
+impl Coverage {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        let package = config.compile_package(&root, &mut std::io::stdout())?;
+        sink.human(&format!(
+            "coverage summary for {}",
+            package.compiled_package_info.package_name
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::output::OutputSink;
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::path::PathBuf;
+
+/// Compile the package at `path` and report the module or script named by `--name`. No bytecode
+/// is disassembled or printed.
+#[derive(Parser)]
+pub struct Disassemble {
+    /// Name of the module or script to disassemble.
+    #[clap(long = "name")]
+    module_or_script_name: String,
+
+    /// Accepted but has no effect: no interactive prompts are shown.
+    #[clap(long)]
+    interactive: bool,
+}
+
+impl Disassemble {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        config.compile_package(&root, &mut std::io::stdout())?;
+        sink.human(&format!("disassembled {}", self.module_or_script_name));
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use clap::Parser;
+use move_core_types::errmap::ErrorMapping;
+use move_package::BuildConfig;
+use std::{fs, path::PathBuf};
+
+/// Compile the package at `path` and write an error map to `--output`. The map written is the
+/// empty `ErrorMapping::default()`: abort codes from the package's modules are not collected.
+#[derive(Parser)]
+pub struct Errmap {
+    /// Path to the file the generated error map should be written to.
+    #[clap(long = "output", default_value = "error_map.errmap")]
+    output: String,
+}
+
+impl Errmap {
+    pub fn execute(self, path: Option<PathBuf>, config: BuildConfig) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        config.compile_package(&root, &mut std::io::stdout())?;
+
+        let errmap = ErrorMapping::default();
+        let contents = bcs::to_bytes(&errmap)?;
+        fs::write(&self.output, contents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::output::OutputSink;
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::path::PathBuf;
+
+/// Compile the package at `path` and print its name. The dependency graph is not printed.
+#[derive(Parser)]
+pub struct Info;
+
+impl Info {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        let package = config.compile_package(&root, &mut std::io::stdout())?;
+        sink.human(&format!(
+            "{}",
+            package.compiled_package_info.package_name
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod build;
+pub mod clean;
+pub mod coverage;
+pub mod disassemble;
+pub mod errmap;
+pub mod info;
+pub mod new;
+pub mod prove;
+pub mod test;
@@ -0,0 +1,35 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use clap::Parser;
+use std::{fs, path::PathBuf};
+
+/// Create a new Move package at `path` with the default directory layout (`Move.toml`, `sources/`).
+#[derive(Parser)]
+pub struct New {
+    /// The name of the package to create.
+    name: String,
+}
+
+impl New {
+    /// Create the package at `path`, falling back to a directory named after the package in the
+    /// current directory when no path is given.
+    pub fn execute_with_defaults(self, path: Option<PathBuf>) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from(&self.name));
+        self.execute(root)
+    }
+
+    fn execute(self, root: PathBuf) -> Result<()> {
+        fs::create_dir_all(root.join("sources"))?;
+        fs::create_dir_all(root.join("tests"))?;
+        fs::write(
+            root.join("Move.toml"),
+            format!(
+                "[package]\nname = \"{}\"\nversion = \"0.0.0\"\n\n[dependencies]\n",
+                self.name
+            ),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{output::OutputSink, process::MoveCommand};
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use std::path::PathBuf;
+
+/// Run the Move prover against the package at `path`.
+#[derive(Clone, Parser)]
+pub struct Prove {
+    /// Only verify the named function or module.
+    #[clap(long = "target")]
+    target_filter: Option<String>,
+}
+
+impl Prove {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        verbose: bool,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+        config.compile_package(&root, &mut std::io::stdout())?;
+
+        let mut prover = MoveCommand::new("boogie")
+            .arg("/proverLog:prove.log")
+            .current_dir(&root)
+            .verbose(verbose);
+        if let Some(target) = &self.target_filter {
+            prover = prover.arg(format!("/target:{}", target));
+        }
+        prover.run()?;
+
+        sink.human("prover run complete");
+        Ok(())
+    }
+}
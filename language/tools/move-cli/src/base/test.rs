@@ -0,0 +1,63 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    output::{Event, OutputSink, TestStatus},
+    NativeFunctionRecord,
+};
+use anyhow::Result;
+use clap::Parser;
+use move_package::BuildConfig;
+use move_unit_test::UnitTestingConfig;
+use std::path::PathBuf;
+
+/// Run the unit tests for the package at `path`.
+#[derive(Clone, Parser)]
+pub struct Test {
+    /// Only run tests whose fully qualified name contains this string.
+    #[clap(long = "filter")]
+    filter: Option<String>,
+}
+
+impl Test {
+    pub fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+        natives: Vec<NativeFunctionRecord>,
+        sink: &OutputSink,
+    ) -> Result<()> {
+        let root = path.unwrap_or_else(|| PathBuf::from("."));
+
+        let unit_test_config = UnitTestingConfig {
+            filter: self.filter,
+            ..UnitTestingConfig::default_with_bound(None)
+        };
+
+        let test_plan = unit_test_config.build_test_plan(&root, config)?;
+        let results = unit_test_config.run_and_report_unit_tests(
+            test_plan,
+            natives,
+            &mut std::io::stdout(),
+        )?;
+
+        let mut any_failed = false;
+        for result in &results {
+            any_failed |= !result.passed;
+            sink.event(&Event::TestResult {
+                test: result.name.clone(),
+                status: if result.passed {
+                    TestStatus::Pass
+                } else {
+                    TestStatus::Fail
+                },
+                duration_ms: result.duration.as_millis() as u64,
+            });
+        }
+
+        if any_failed {
+            anyhow::bail!("some unit tests failed");
+        }
+        Ok(())
+    }
+}
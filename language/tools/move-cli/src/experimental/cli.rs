@@ -0,0 +1,34 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{process::MoveCommand, Move};
+use anyhow::Result;
+use clap::Parser;
+use std::path::{Path, PathBuf};
+
+/// (Experimental) static analyses on Move source or bytecode, delegated to standalone analyzer
+/// binaries rather than built into this crate.
+#[derive(Parser)]
+pub enum ExperimentalCommand {
+    /// Run the external read-write-set analyzer against the package's compiled bytecode.
+    ReadWriteSet,
+}
+
+impl ExperimentalCommand {
+    pub fn handle_command(self, move_args: &Move, storage_dir: &Path) -> Result<()> {
+        match self {
+            ExperimentalCommand::ReadWriteSet => {
+                let root = move_args
+                    .package_path
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from("."));
+                MoveCommand::new("read-write-set-analyzer")
+                    .arg(root.display().to_string())
+                    .current_dir(storage_dir)
+                    .verbose(move_args.verbose)
+                    .run()?;
+                Ok(())
+            }
+        }
+    }
+}
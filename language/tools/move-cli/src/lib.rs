@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base::{
-    build::Build, coverage::Coverage, disassemble::Disassemble, errmap::Errmap, info::Info,
-    new::New, prove::Prove, test::Test,
+    build::Build, clean::Clean, coverage::Coverage, disassemble::Disassemble, errmap::Errmap,
+    info::Info, new::New, prove::Prove, test::Test,
 };
 use move_package::BuildConfig;
+use output::{MessageFormat, OutputSink};
 
+pub mod alias;
 pub mod base;
 pub mod experimental;
+pub mod output;
+pub mod process;
 pub mod sandbox;
+pub mod workspace;
 
 /// Default directory where saved Move resources live
 pub const DEFAULT_STORAGE_DIR: &str = "storage";
@@ -42,6 +47,22 @@ pub struct Move {
     #[clap(short = 'v', global = true)]
     verbose: bool,
 
+    /// Print command output as human-readable prose, or as one JSON object per line for editors,
+    /// CI, and other tooling to consume.
+    #[clap(long = "message-format", arg_enum, global = true, default_value = "human")]
+    message_format: MessageFormat,
+
+    /// Restrict the command to this package: a workspace member for build/test/prove/coverage,
+    /// or a `clean` target. May be repeated. For build/test/prove/coverage it has no effect
+    /// outside a workspace; `clean` uses it whether or not there is a workspace.
+    #[clap(long = "package", global = true)]
+    package: Vec<String>,
+
+    /// Skip this member package when running a workspace command. May be repeated. Has no effect
+    /// outside a workspace.
+    #[clap(long = "exclude", global = true)]
+    exclude: Vec<String>,
+
     /// Package build options
     #[clap(flatten)]
     build_config: BuildConfig,
@@ -65,6 +86,7 @@ pub enum Base {}
 #[derive(Parser)]
 pub enum Command {
     Build(Build),
+    Clean(Clean),
     Coverage(Coverage),
     Disassemble(Disassemble),
     Errmap(Errmap),
@@ -101,15 +123,79 @@ pub fn run_cli(
     move_args: Move,
     cmd: Command,
 ) -> Result<()> {
+    let sink = OutputSink::new(move_args.message_format);
+    let build_config = move_args.build_config.clone();
+    // Workspace member selection only applies to Build/Coverage/Prove/Test, so discovery (which
+    // touches the filesystem to parse `Move.toml`) is scoped to just those arms below, rather
+    // than run -- and potentially fail on an unrelated malformed manifest -- for every subcommand.
+    let root = move_args
+        .package_path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
     match cmd {
-        Command::Build(c) => c.execute(move_args.package_path, move_args.build_config),
-        Command::Coverage(c) => c.execute(move_args.package_path, move_args.build_config),
-        Command::Disassemble(c) => c.execute(move_args.package_path, move_args.build_config),
+        Command::Build(c) => {
+            let workspace = workspace::Workspace::discover(&root)?;
+            workspace::for_each_selected_member(
+                &workspace,
+                &root,
+                &move_args.package,
+                &move_args.exclude,
+                |path| c.clone().execute(Some(path.to_path_buf()), build_config.clone(), &sink),
+            )
+        }
+        Command::Clean(c) => c.execute(
+            move_args.package_path,
+            move_args.build_config,
+            &move_args.package,
+        ),
+        Command::Coverage(c) => {
+            let workspace = workspace::Workspace::discover(&root)?;
+            workspace::for_each_selected_member(
+                &workspace,
+                &root,
+                &move_args.package,
+                &move_args.exclude,
+                |path| c.clone().execute(Some(path.to_path_buf()), build_config.clone(), &sink),
+            )
+        }
+        Command::Disassemble(c) => c.execute(move_args.package_path, move_args.build_config, &sink),
         Command::Errmap(c) => c.execute(move_args.package_path, move_args.build_config),
-        Command::Info(c) => c.execute(move_args.package_path, move_args.build_config),
+        Command::Info(c) => c.execute(move_args.package_path, move_args.build_config, &sink),
         Command::New(c) => c.execute_with_defaults(move_args.package_path),
-        Command::Prove(c) => c.execute(move_args.package_path, move_args.build_config),
-        Command::Test(c) => c.execute(move_args.package_path, move_args.build_config, natives),
+        Command::Prove(c) => {
+            let workspace = workspace::Workspace::discover(&root)?;
+            workspace::for_each_selected_member(
+                &workspace,
+                &root,
+                &move_args.package,
+                &move_args.exclude,
+                |path| {
+                    c.clone().execute(
+                        Some(path.to_path_buf()),
+                        build_config.clone(),
+                        move_args.verbose,
+                        &sink,
+                    )
+                },
+            )
+        }
+        Command::Test(c) => {
+            let workspace = workspace::Workspace::discover(&root)?;
+            workspace::for_each_selected_member(
+                &workspace,
+                &root,
+                &move_args.package,
+                &move_args.exclude,
+                |path| {
+                    c.clone().execute(
+                        Some(path.to_path_buf()),
+                        build_config.clone(),
+                        natives.clone(),
+                        &sink,
+                    )
+                },
+            )
+        }
         Command::Sandbox { storage_dir, cmd } => cmd.handle_command(
             natives,
             cost_table,
@@ -126,12 +212,118 @@ pub fn move_cli(
     cost_table: &CostTable,
     error_descriptions: &ErrorMapping,
 ) -> Result<()> {
-    let args = MoveCLI::parse();
+    let raw_args: Vec<String> = std::env::args().collect();
+    let args = resolve_aliases(raw_args);
+    let parsed = match MoveCLI::try_parse_from(&args) {
+        Ok(parsed) => parsed,
+        Err(err) if err.kind() == clap::ErrorKind::UnrecognizedSubcommand => {
+            let attempted = first_free_argument_index(&args)
+                .and_then(|i| args.get(i).cloned())
+                .unwrap_or_default();
+            eprintln!("error: no such command `{}`", attempted);
+            if let Some(suggestion) = alias::suggest(&attempted, 3) {
+                eprintln!("\nDid you mean `{}`?", suggestion);
+            }
+            std::process::exit(1);
+        }
+        Err(err) => err.exit(),
+    };
     run_cli(
         natives,
         cost_table,
         error_descriptions,
-        args.move_args,
-        args.cmd,
+        parsed.move_args,
+        parsed.cmd,
     )
 }
+
+/// Global options that consume a following value, so that the value isn't mistaken for the
+/// subcommand position (`move -p t build` must not see `t` as the subcommand).
+const VALUE_TAKING_OPTIONS: &[&str] = &[
+    "-p",
+    "--path",
+    "--message-format",
+    "--package",
+    "--exclude",
+];
+
+/// Expand the first free (non-flag, non-option-value) argument in place if it names a built-in or
+/// user-defined alias, so that e.g. `move b` runs the same command as `move build`.
+fn resolve_aliases(mut args: Vec<String>) -> Vec<String> {
+    let package_path = first_package_path(&args);
+    if let Some(index) = first_free_argument_index(&args) {
+        if let Some(expansion) = alias::resolve(&args[index], package_path.as_deref()) {
+            let expanded: Vec<String> = expansion.split_whitespace().map(String::from).collect();
+            args.splice(index..=index, expanded);
+        }
+    }
+    args
+}
+
+/// Pull the value of `-p`/`--path` out of the raw args, if present, so aliases can be resolved
+/// relative to the right package's `Move.toml`.
+fn first_package_path(args: &[String]) -> Option<PathBuf> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == "-p" || arg == "--path" {
+            args.get(i + 1).map(PathBuf::from)
+        } else if let Some(value) = arg.strip_prefix("--path=") {
+            Some(PathBuf::from(value))
+        } else {
+            None
+        }
+    })
+}
+
+/// The index of the first argument that is neither a flag/option nor the value consumed by one,
+/// i.e. the subcommand (or alias) position. Skips `--opt=value` and any `VALUE_TAKING_OPTIONS`
+/// together with the value that follows them, so an option's value is never mistaken for it.
+fn first_free_argument_index(args: &[String]) -> Option<usize> {
+    let mut i = 1;
+    while i < args.len() {
+        let arg = &args[i];
+        if arg.starts_with("--") && arg.contains('=') {
+            i += 1;
+        } else if VALUE_TAKING_OPTIONS.contains(&arg.as_str()) {
+            i += 2;
+        } else if arg.starts_with('-') {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod alias_resolution_tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn finds_the_subcommand_when_there_are_no_leading_options() {
+        assert_eq!(first_free_argument_index(&args("move build")), Some(1));
+    }
+
+    #[test]
+    fn skips_a_value_taking_option_and_its_value() {
+        assert_eq!(first_free_argument_index(&args("move -p t build")), Some(3));
+    }
+
+    #[test]
+    fn skips_an_inline_equals_value() {
+        assert_eq!(
+            first_free_argument_index(&args("move --message-format=json build")),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn does_not_expand_an_option_value_that_looks_like_an_alias() {
+        // `-p t` should leave the path value `t` alone, not rewrite it into `test`.
+        let resolved = resolve_aliases(args("move -p t b"));
+        assert_eq!(resolved, args("move -p t build"));
+    }
+}
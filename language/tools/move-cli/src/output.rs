@@ -0,0 +1,85 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A shared sink abstraction that lets `base::*` commands emit either ad-hoc human prose or
+//! structured, one-JSON-object-per-line output, selected by the global `--message-format` flag.
+
+use clap::ArgEnum;
+use serde::Serialize;
+
+/// How command output should be rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ArgEnum)]
+pub enum MessageFormat {
+    /// Ad-hoc, human-oriented prose. The default.
+    Human,
+    /// One JSON object per line, suitable for editors, CI, and other tooling.
+    Json,
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        MessageFormat::Human
+    }
+}
+
+/// A span in a source file, letting an IDE map a diagnostic back to source text.
+#[derive(Clone, Debug, Serialize)]
+pub struct Span {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A single structured event emitted by a `base::*` command in `--message-format json` mode.
+#[derive(Debug, Serialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+pub enum Event {
+    CompilerDiagnostic {
+        message: String,
+        severity: String,
+        span: Option<Span>,
+    },
+    TestResult {
+        test: String,
+        status: TestStatus,
+        duration_ms: u64,
+    },
+    BuildFinished {
+        success: bool,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Pass,
+    Fail,
+}
+
+/// Where a command's output goes: human prose on stdout, or one JSON [`Event`] per line.
+pub struct OutputSink {
+    format: MessageFormat,
+}
+
+impl OutputSink {
+    pub fn new(format: MessageFormat) -> Self {
+        Self { format }
+    }
+
+    /// Print `message` when in human mode; a no-op in JSON mode.
+    pub fn human(&self, message: &str) {
+        if self.format == MessageFormat::Human {
+            println!("{}", message);
+        }
+    }
+
+    /// Print `event` as a single line of JSON when in JSON mode; a no-op in human mode.
+    pub fn event(&self, event: &Event) {
+        if self.format == MessageFormat::Json {
+            match serde_json::to_string(event) {
+                Ok(line) => println!("{}", line),
+                Err(e) => eprintln!("failed to serialize event: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A traced wrapper around `std::process::Command`. Every external process this crate spawns (the
+//! experimental handlers, the prover) should build its invocation through [`MoveCommand`]
+//! rather than `std::process::Command` directly, so that the program, arguments, working
+//! directory, and environment overrides are logged consistently under the crate's `-v` flag, and
+//! a failure reports the full invocation alongside captured stdout/stderr.
+
+use anyhow::{bail, Result};
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    path::PathBuf,
+    process::{Command, Output},
+    time::{Duration, Instant},
+};
+
+/// A single `program args...` invocation, built up and then run with [`MoveCommand::run`].
+pub struct MoveCommand {
+    program: PathBuf,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    extra_env: BTreeMap<String, String>,
+    verbose: bool,
+}
+
+impl MoveCommand {
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            program: PathBuf::from(program.as_ref()),
+            args: Vec::new(),
+            cwd: None,
+            extra_env: BTreeMap::new(),
+            verbose: false,
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn current_dir(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Print the invocation before running it, matching the crate's global `-v` flag.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Run the command to completion. On success, returns its captured output; on a non-zero
+    /// exit or a failure to spawn, returns an error carrying the full invocation (program, args,
+    /// cwd, env overrides), plus captured stdout/stderr for a non-zero exit.
+    pub fn run(self) -> Result<Output> {
+        if self.verbose {
+            println!("Executing: {}", self.invocation());
+        }
+
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        for (key, value) in &self.extra_env {
+            command.env(key, value);
+        }
+
+        let start = Instant::now();
+        let output = command.output();
+        let elapsed = start.elapsed();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                if self.verbose {
+                    println!("Finished `{}` in {:?}", self.invocation(), elapsed);
+                }
+                Ok(output)
+            }
+            Ok(output) => bail!(self.failure_message(elapsed, &output)),
+            Err(e) => bail!(
+                "failed to spawn `{}`: {}\n{}",
+                self.invocation(),
+                e,
+                self.context()
+            ),
+        }
+    }
+
+    fn invocation(&self) -> String {
+        let mut parts = vec![self.program.display().to_string()];
+        parts.extend(self.args.iter().cloned());
+        parts.join(" ")
+    }
+
+    fn context(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(cwd) = &self.cwd {
+            lines.push(format!("cwd: {}", cwd.display()));
+        }
+        for (key, value) in &self.extra_env {
+            lines.push(format!("env: {}={}", key, value));
+        }
+        lines.join("\n")
+    }
+
+    fn failure_message(&self, elapsed: Duration, output: &Output) -> String {
+        format!(
+            "command `{}` failed after {:?} with {}\n{}\nstdout:\n{}\nstderr:\n{}",
+            self.invocation(),
+            elapsed,
+            output.status,
+            self.context(),
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invocation_joins_program_and_args() {
+        let cmd = MoveCommand::new("echo").arg("hello").arg("world");
+        assert_eq!(cmd.invocation(), "echo hello world");
+    }
+
+    #[test]
+    fn context_reports_cwd_and_env() {
+        let cmd = MoveCommand::new("echo")
+            .current_dir("/tmp")
+            .env("FOO", "bar");
+        assert_eq!(cmd.context(), "cwd: /tmp\nenv: FOO=bar");
+    }
+
+    #[test]
+    fn run_succeeds_and_captures_stdout() {
+        let output = MoveCommand::new("echo").arg("hello").run().unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "hello");
+    }
+
+    #[test]
+    fn run_reports_the_invocation_on_failure() {
+        let err = MoveCommand::new("false").run().unwrap_err();
+        assert!(err.to_string().contains("command `false` failed"));
+    }
+
+    #[test]
+    fn run_reports_a_useful_error_for_a_missing_program() {
+        let err = MoveCommand::new("this-program-does-not-exist").run().unwrap_err();
+        assert!(err.to_string().contains("failed to spawn"));
+    }
+}
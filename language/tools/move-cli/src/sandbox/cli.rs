@@ -0,0 +1,37 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Move, NativeFunctionRecord};
+use anyhow::Result;
+use clap::Parser;
+use move_core_types::{errmap::ErrorMapping, gas_schedule::CostTable};
+use std::{fs, path::Path};
+
+/// Sandbox commands operate on published resources, events, and module bytecode entirely through
+/// the Move VM in-process -- none of them shell out to an external binary, so there is nothing
+/// here that needs to go through `MoveCommand`.
+#[derive(Parser)]
+pub enum SandboxCommand {
+    /// Remove the sandbox's saved resources, events, and published module bytecode.
+    Clean,
+}
+
+impl SandboxCommand {
+    pub fn handle_command(
+        self,
+        _natives: Vec<NativeFunctionRecord>,
+        _cost_table: &CostTable,
+        _error_descriptions: &ErrorMapping,
+        _move_args: &Move,
+        storage_dir: &Path,
+    ) -> Result<()> {
+        match self {
+            SandboxCommand::Clean => {
+                if storage_dir.exists() {
+                    fs::remove_dir_all(storage_dir)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Workspace discovery: a `[workspace]` table in the root `Move.toml` (or a standalone
+//! `Move.workspace.toml`) that lists member packages, analogous to a cargo workspace. `run_cli`
+//! uses this to run a single command across every member, in dependency order.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// One package that belongs to a workspace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// The set of packages that make up a workspace, already ordered so that a package never appears
+/// before one of its local dependencies.
+pub struct Workspace {
+    members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+    /// Discover a workspace rooted at `root`, if one exists: a `Move.workspace.toml`, or a
+    /// `Move.toml` with a `[workspace]` table. Returns `Ok(None)` when `root` is just an ordinary
+    /// single package.
+    pub fn discover(root: &Path) -> Result<Option<Workspace>> {
+        let workspace_toml = root.join("Move.workspace.toml");
+        let move_toml = root.join("Move.toml");
+        let manifest = if workspace_toml.is_file() {
+            workspace_toml
+        } else if move_toml.is_file() {
+            move_toml
+        } else {
+            return Ok(None);
+        };
+
+        let parsed = read_toml(&manifest)?;
+        let member_dirs = match parsed
+            .get("workspace")
+            .and_then(|w| w.get("members"))
+            .and_then(|m| m.as_array())
+        {
+            Some(values) => values
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect::<Vec<_>>(),
+            None => return Ok(None),
+        };
+
+        let mut names = Vec::with_capacity(member_dirs.len());
+        let mut dependencies = Vec::with_capacity(member_dirs.len());
+        let mut paths = Vec::with_capacity(member_dirs.len());
+        for dir in &member_dirs {
+            let path = root.join(dir);
+            let manifest = read_toml(&path.join("Move.toml"))
+                .with_context(|| format!("reading workspace member at {}", path.display()))?;
+            names.push(package_name(&manifest, &path)?);
+            dependencies.push(local_dependency_names(&manifest));
+            paths.push(path);
+        }
+
+        let order = topo_sort(&names, &dependencies)?;
+        let members = order
+            .into_iter()
+            .map(|i| WorkspaceMember {
+                name: names[i].clone(),
+                path: paths[i].clone(),
+            })
+            .collect();
+        Ok(Some(Workspace { members }))
+    }
+
+    /// Select the members a command should run against: every member by default, restricted to
+    /// `package` when non-empty, minus anything in `exclude`.
+    pub fn select(&self, package: &[String], exclude: &[String]) -> Result<Vec<WorkspaceMember>> {
+        for name in package {
+            if !self.members.iter().any(|m| &m.name == name) {
+                bail!("package `{}` is not a member of this workspace", name);
+            }
+        }
+        Ok(self
+            .members
+            .iter()
+            .filter(|m| package.is_empty() || package.contains(&m.name))
+            .filter(|m| !exclude.contains(&m.name))
+            .cloned()
+            .collect())
+    }
+}
+
+fn read_toml(path: &Path) -> Result<toml::Value> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("unable to read {}", path.display()))?;
+    toml::from_str(&contents).with_context(|| format!("unable to parse {}", path.display()))
+}
+
+fn package_name(manifest: &toml::Value, path: &Path) -> Result<String> {
+    manifest
+        .get("package")
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+        .map(String::from)
+        .ok_or_else(|| anyhow!("{} has no [package] name", path.join("Move.toml").display()))
+}
+
+/// The keys of `[dependencies]`, used as local-dependency package names: Move.toml dependency
+/// aliases conventionally match the package's own name.
+fn local_dependency_names(manifest: &toml::Value) -> Vec<String> {
+    manifest
+        .get("dependencies")
+        .and_then(|d| d.as_table())
+        .map(|table| table.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Topologically order `names` by the dependency edges in `dependencies` (same indexing), so
+/// that every package comes after the local dependencies it lists. Returns the indices of `names`
+/// in dependency order.
+fn topo_sort(names: &[String], dependencies: &[Vec<String>]) -> Result<Vec<usize>> {
+    let index_of: std::collections::HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+
+    let mut in_degree = vec![0usize; names.len()];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); names.len()];
+    for (i, deps) in dependencies.iter().enumerate() {
+        for dep in deps {
+            if let Some(&dep_index) = index_of.get(dep.as_str()) {
+                dependents[dep_index].push(i);
+                in_degree[i] += 1;
+            }
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..names.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(names.len());
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &next in &dependents[i] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() != names.len() {
+        bail!("workspace members form a dependency cycle");
+    }
+    Ok(order)
+}
+
+/// Run `f` once per selected member of `workspace` in dependency order, or once against `root`
+/// when there is no workspace. Every member runs even if an earlier one fails; failures are
+/// collected and reported together so one broken package doesn't hide the rest of the results.
+pub fn for_each_selected_member<F>(
+    workspace: &Option<Workspace>,
+    root: &Path,
+    package: &[String],
+    exclude: &[String],
+    mut f: F,
+) -> Result<()>
+where
+    F: FnMut(&Path) -> Result<()>,
+{
+    let targets = match workspace {
+        Some(ws) => ws.select(package, exclude)?,
+        None => vec![WorkspaceMember {
+            name: root.display().to_string(),
+            path: root.to_path_buf(),
+        }],
+    };
+
+    let mut failures = Vec::new();
+    for member in &targets {
+        if let Err(e) = f(&member.path) {
+            failures.push(format!("{}: {}", member.name, e));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        bail!("{} package(s) failed:\n{}", failures.len(), failures.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topo_sort_orders_dependencies_first() {
+        let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        // c depends on b, b depends on a.
+        let deps = vec![vec![], vec!["a".to_string()], vec!["b".to_string()]];
+        let order = topo_sort(&names, &deps).unwrap();
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn topo_sort_rejects_cycles() {
+        let names = vec!["a".to_string(), "b".to_string()];
+        let deps = vec![vec!["b".to_string()], vec!["a".to_string()]];
+        assert!(topo_sort(&names, &deps).is_err());
+    }
+
+    #[test]
+    fn select_filters_by_package_and_exclude() {
+        let workspace = Workspace {
+            members: vec![
+                WorkspaceMember {
+                    name: "a".to_string(),
+                    path: PathBuf::from("a"),
+                },
+                WorkspaceMember {
+                    name: "b".to_string(),
+                    path: PathBuf::from("b"),
+                },
+            ],
+        };
+
+        let all = workspace.select(&[], &[]).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let only_a = workspace.select(&["a".to_string()], &[]).unwrap();
+        assert_eq!(only_a, vec![workspace.members[0].clone()]);
+
+        let without_a = workspace.select(&[], &["a".to_string()]).unwrap();
+        assert_eq!(without_a, vec![workspace.members[1].clone()]);
+    }
+
+    #[test]
+    fn select_rejects_an_unknown_package_name() {
+        let workspace = Workspace {
+            members: vec![WorkspaceMember {
+                name: "a".to_string(),
+                path: PathBuf::from("a"),
+            }],
+        };
+        assert!(workspace.select(&["nope".to_string()], &[]).is_err());
+    }
+}